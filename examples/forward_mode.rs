@@ -0,0 +1,64 @@
+use std::ops::{Deref, DerefMut};
+
+use derive_overwrites::*;
+
+struct MyStruct {
+    pub count: usize,
+}
+
+// With `forward`, every generated trait method gets a default body that
+// derefs through to the original method, instead of being left unimplemented.
+// That means a wrapper only needs to write an empty `impl ... for Wrapper {}`
+// to opt in to the original behavior, and can override just the methods it
+// actually wants to change.
+#[generate_overwrites(forward)]
+impl MyStruct {
+    pub fn increment_by(&mut self, amount: usize) {
+        self.count += amount;
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+struct MyWrapper {
+    pub inner: MyStruct,
+}
+
+impl Deref for MyWrapper {
+    type Target = MyStruct;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for MyWrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+// No method bodies needed here: `forward`'s default bodies already deref to
+// `MyStruct`'s originals.
+impl MyStructOverwrites for MyWrapper {
+    // Override just this one method; `reset` keeps the forwarded default.
+    fn increment_by(&mut self, amount: usize) {
+        println!("OVERWRITTEN: incrementing by {amount}");
+        self.inner.increment_by(amount);
+    }
+}
+
+fn main() {
+    let mut w = MyWrapper {
+        inner: MyStruct { count: 0 },
+    };
+
+    w.increment_by(3);
+    println!("count after overwritten increment_by: {}", w.inner.count);
+
+    // Falls through to the forwarded default, which derefs to MyStruct::reset.
+    w.reset();
+    println!("count after forwarded reset: {}", w.inner.count);
+}
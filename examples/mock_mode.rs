@@ -0,0 +1,36 @@
+use derive_overwrites::*;
+
+struct PaymentProcessor {
+    balance: i64,
+}
+
+// `mock` generates a `Mock{Name}` test double alongside the trait: a struct
+// with one `expect_*` setter per method (taking a closure) and one `*_calls`
+// accessor for asserting call counts, in addition to the usual trait + impl.
+#[generate_overwrites(mock)]
+impl PaymentProcessor {
+    pub fn charge(&mut self, amount: i64) -> bool {
+        if self.balance >= amount {
+            self.balance -= amount;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn run_checkout(processor: &mut impl PaymentProcessorOverwrites, amount: i64) -> bool {
+    processor.charge(amount)
+}
+
+fn main() {
+    let mut real = PaymentProcessor { balance: 20 };
+    println!("real processor rejects overdraft: {}", !real.charge(50));
+
+    let mut mock = MockPaymentProcessor::new();
+    mock.expect_charge(|amount| amount <= 100);
+
+    let approved = run_checkout(&mut mock, 50);
+    println!("approved: {approved}");
+    println!("charge calls: {}", mock.charge_calls());
+}
@@ -0,0 +1,40 @@
+use derive_overwrites::*;
+
+struct MyStruct {
+    pub count: usize,
+}
+
+// Pair `forward` with `overwrite_wrapper`: `forward` gives every generated
+// trait method a default body (deref to the original), so the wrapper's
+// `impl ... for Wrapper {}` below can stay empty.
+#[generate_overwrites(forward)]
+impl MyStruct {
+    pub fn increment_by(&mut self, amount: usize) {
+        self.count += amount;
+    }
+}
+
+// Generates `Deref`/`DerefMut` to the `inner` field, a `new(...)` constructor,
+// and a `From<(...)>` impl, but no blank trait impl: whether
+// `impl MyStructOverwrites for MyWrapper {}` compiles depends on how (or
+// whether) `MyStructOverwrites` was generated, which this macro has no
+// visibility into. Write it yourself once `forward` makes it valid.
+#[overwrite_wrapper]
+struct MyWrapper {
+    pub label: String,
+    pub inner: MyStruct,
+}
+
+impl MyStructOverwrites for MyWrapper {
+    fn increment_by(&mut self, amount: usize) {
+        println!("[{}] incrementing by {amount}", self.label);
+        self.inner.increment_by(amount);
+    }
+}
+
+fn main() {
+    let mut w = MyWrapper::new("wrapper".to_string(), MyStruct { count: 0 });
+
+    w.increment_by(4);
+    println!("count: {}", w.inner.count);
+}
@@ -0,0 +1,46 @@
+use derive_overwrites::*;
+
+struct MyStruct {
+    pub count: usize,
+}
+
+// `trace` wraps each passthrough method body in a `tracing` span covering
+// entry and exit, recording every argument as a `Debug` field by default.
+// Use `#[skip(arg1, arg2)]` on a method (the same attribute that, written
+// bare, opts a method out of generation, reused here for its argument-list
+// form) to leave specific arguments out of the span, e.g. to avoid logging
+// secrets.
+// The inherent methods below are only ever called through the trait (see
+// `main`), so rustc's dead-code lint doesn't see them as used.
+#[allow(dead_code)]
+#[generate_overwrites(passthrough, trace)]
+impl MyStruct {
+    pub fn increment_by(&mut self, amount: usize) {
+        self.count += amount;
+    }
+
+    #[skip(password)]
+    pub fn login(&mut self, user: &str, password: &str) {
+        println!("{user} logged in");
+        let _ = password;
+    }
+}
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::ENTER)
+        .init();
+
+    let mut s = MyStruct { count: 0 };
+    // `passthrough` puts the traced bodies on `impl MyStructOverwrites for
+    // MyStruct`, so calling through the generated trait (rather than the
+    // inherent method, which Rust would otherwise prefer) is what actually
+    // runs the traced version.
+    // Recorded as `MyStruct::increment_by { amount = 5 }`.
+    MyStructOverwrites::increment_by(&mut s, 5);
+    // Recorded as `MyStruct::login { user = "alice" }` — `password` is skipped.
+    MyStructOverwrites::login(&mut s, "alice", "hunter2");
+
+    println!("count: {}", s.count);
+}
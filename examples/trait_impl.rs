@@ -0,0 +1,37 @@
+use derive_overwrites::*;
+
+struct Countdown {
+    remaining: usize,
+}
+
+// `generate_overwrites` also supports `impl Trait for Type`, not just
+// inherent impls. The generated trait is named `{Trait}{Type}Overwrites`,
+// and with `passthrough` it gets its own impl for `Countdown` so the
+// overwritten trait keeps behaving like the original `Iterator` impl.
+//
+// Associated types/consts on the source impl (`type Item` here) are
+// forwarded onto the generated trait so idiomatic signatures like
+// `Option<Self::Item>` keep working.
+#[generate_overwrites(passthrough)]
+impl Iterator for Countdown {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            Some(self.remaining)
+        }
+    }
+}
+
+fn main() {
+    let mut countdown = Countdown { remaining: 3 };
+
+    // Calling through the generated trait rather than `std::iter::Iterator`
+    // directly, since both are in scope for `Countdown`.
+    while let Some(value) = IteratorCountdownOverwrites::next(&mut countdown) {
+        println!("{value}");
+    }
+}
@@ -1,7 +1,8 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
-    Ident, ImplItem, ItemImpl, LitStr, Result, Token, Type,
+    Fields, FnArg, Ident, ImplItem, ItemImpl, ItemStruct, LitStr, Pat, Result, ReturnType, Token,
+    Type,
     parse::{Parse, ParseStream},
     parse_macro_input,
     spanned::Spanned,
@@ -17,15 +18,200 @@ pub fn overwrite(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+#[proc_macro_attribute]
+pub fn overwrite_wrapper(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let OverwriteWrapperArgs { inner } = parse_macro_input!(attr as OverwriteWrapperArgs);
+    let item_struct = parse_macro_input!(item as ItemStruct);
+
+    let inner_field_name = inner.unwrap_or_else(|| Ident::new("inner", item_struct.ident.span()));
+
+    let fields = match &item_struct.fields {
+        Fields::Named(fields) => &fields.named,
+        _ => {
+            return TokenStream::from(quote_spanned_error(
+                item_struct.span(),
+                "overwrite_wrapper only supports structs with named fields",
+            ));
+        }
+    };
+
+    let Some(inner_field) = fields
+        .iter()
+        .find(|field| field.ident.as_ref() == Some(&inner_field_name))
+    else {
+        return TokenStream::from(quote_spanned_error(
+            inner_field_name.span(),
+            &format!("No field named `{inner_field_name}` found on this struct"),
+        ));
+    };
+
+    let inner_ty = &inner_field.ty;
+    let struct_ident = &item_struct.ident;
+    let generics = &item_struct.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let field_idents: Vec<&Ident> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let field_types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+
+    let deref_impl = quote! {
+        impl #impl_generics ::std::ops::Deref for #struct_ident #ty_generics #where_clause {
+            type Target = #inner_ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#inner_field_name
+            }
+        }
+
+        impl #impl_generics ::std::ops::DerefMut for #struct_ident #ty_generics #where_clause {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.#inner_field_name
+            }
+        }
+    };
+
+    let ctor_impl = quote! {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            pub fn new(#(#field_idents: #field_types),*) -> Self {
+                Self {
+                    #(#field_idents),*
+                }
+            }
+        }
+
+        impl #impl_generics From<(#(#field_types),*)> for #struct_ident #ty_generics #where_clause {
+            fn from((#(#field_idents),*): (#(#field_types),*)) -> Self {
+                Self {
+                    #(#field_idents),*
+                }
+            }
+        }
+    };
+
+    // No blank `impl {Inner}Overwrites for Wrapper {}` stub is emitted here:
+    // whether that impl compiles depends on whether the `Overwrites` trait
+    // was generated with `forward` (giving every method a default body), and
+    // this macro has no visibility into how (or whether) that trait was
+    // generated. Users compose the two macros by writing that impl
+    // themselves once they know it's valid.
+    let expanded = quote! {
+        #item_struct
+
+        #deref_impl
+
+        #ctor_impl
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn quote_spanned_error(span: proc_macro2::Span, message: &str) -> proc_macro2::TokenStream {
+    quote::quote_spanned! { span => compile_error!(#message); }
+}
+
+struct OverwriteWrapperArgs {
+    inner: Option<Ident>,
+}
+
+impl Parse for OverwriteWrapperArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut inner = None;
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+
+            match ident.to_string().as_str() {
+                "inner" => {
+                    input.parse::<Token![=]>()?;
+                    let value: Ident = input.parse()?;
+                    inner = Some(value);
+                }
+                _ => {
+                    return Err(syn::Error::new(ident.span(), "Unknown argument"));
+                }
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(OverwriteWrapperArgs { inner })
+    }
+}
+
 #[proc_macro_attribute]
 pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream {
     let GenerateArgs {
         all,
         passthrough,
         name,
+        forward,
+        mock,
+        trace,
     } = syn::parse_macro_input!(attr as GenerateArgs);
     let impl_block = parse_macro_input!(item as ItemImpl);
 
+    if forward && (mock || passthrough) {
+        // `forward` adds a `DerefMut<Target = #self_ty>` supertrait bound so its
+        // default method bodies can deref to the real method. `passthrough` and
+        // `mock` each provide their own impl of the generated trait (for
+        // `#self_ty` and `Mock{Name}` respectively), and neither of those types
+        // implements that bound, so the combination fails downstream with a
+        // confusing `DerefMut is not satisfied` pointing at the macro
+        // invocation instead of the real cause. Reject it up front instead.
+        let message =
+            "`forward` cannot be combined with `passthrough` or `mock`: `forward`'s default \
+             method bodies require the implementing type to be `DerefMut` with the original \
+             type as its `Target`, which neither `passthrough`'s impl (for the original type) nor `mock`'s impl (for the \
+             generated mock struct) satisfies. Pick one of `forward`, `passthrough`, or `mock`.";
+        let expanded = quote! {
+            compile_error!(#message);
+
+            #impl_block
+        };
+        return TokenStream::from(expanded);
+    }
+
+    if trace && !passthrough {
+        // `trace` only wraps the bodies `passthrough` emits (see the
+        // `impl_methods`/`traced_method` branch below); without `passthrough`
+        // there are no bodies to wrap, so `trace` alone would otherwise
+        // compile fine and silently do nothing.
+        let message = "`trace` has no effect without `passthrough`: it wraps the method bodies \
+                        `passthrough` emits, and there's nothing to wrap otherwise. Add \
+                        `passthrough` alongside `trace`.";
+        let expanded = quote! {
+            compile_error!(#message);
+
+            #impl_block
+        };
+        return TokenStream::from(expanded);
+    }
+
+    if mock && !impl_block.generics.params.is_empty() {
+        // `build_mock` emits a non-generic `Mock{Name}` struct/impl, but the
+        // generated trait is generic over the source impl's params, and
+        // method signatures may reference them (`T` in `fn get(&self) -> T`).
+        // Properly supporting this means threading impl_generics/ty_generics
+        // through the mock struct, its closure field types, and its impl,
+        // which would in turn need real bounds on those closures - not a
+        // mechanical change. Reject up front instead of letting it surface
+        // as "cannot find type `T`"/"missing generics for trait".
+        let message = "`mock` does not support generic impls: the generated `Mock{Name}` struct \
+                        is not generic, so method signatures referencing the impl's type \
+                        parameters can't be expressed on it. Drop `mock` for generic impls, or \
+                        mock a concrete instantiation by hand.";
+        let expanded = quote! {
+            compile_error!(#message);
+
+            #impl_block
+        };
+        return TokenStream::from(expanded);
+    }
+
     let self_ty = &impl_block.self_ty;
 
     let struct_name = match self_ty.as_ref() {
@@ -33,19 +219,61 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
         _ => None,
     };
 
+    let implemented_trait_name = impl_block
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .map(|seg| &seg.ident);
+
     let trait_name = if let Some(name) = name {
         Some(syn::Ident::new(&name, struct_name.span()))
+    } else if let Some(implemented_trait_name) = implemented_trait_name {
+        struct_name.map(|name| {
+            syn::Ident::new(
+                &format!("{implemented_trait_name}{name}Overwrites"),
+                name.span(),
+            )
+        })
     } else {
         struct_name.map(|name| syn::Ident::new(&format!("{name}Overwrites"), name.span()))
     };
 
+    // Associated types/consts from the source impl (e.g. `type Item` on an
+    // `impl Iterator for ...`) aren't gated by `#[skip]`/`#[overwrite]` like
+    // methods are: the generated trait's method signatures may depend on
+    // them (`Option<Self::Item>`), so they're always forwarded. The trait
+    // only gets a bare declaration; the concrete definition is reused
+    // wherever this crate emits an impl of that trait (passthrough, mock).
+    let mut assoc_decls = Vec::new();
+    let mut assoc_defs = Vec::new();
+
+    for item in &impl_block.items {
+        match item {
+            ImplItem::Type(assoc_type) => {
+                let ident = &assoc_type.ident;
+                let ty = &assoc_type.ty;
+                assoc_decls.push(quote! { type #ident; });
+                assoc_defs.push(quote! { type #ident = #ty; });
+            }
+            ImplItem::Const(assoc_const) => {
+                let ident = &assoc_const.ident;
+                let const_ty = &assoc_const.ty;
+                let expr = &assoc_const.expr;
+                assoc_decls.push(quote! { const #ident: #const_ty; });
+                assoc_defs.push(quote! { const #ident: #const_ty = #expr; });
+            }
+            _ => {}
+        }
+    }
+
     let mut trait_methods = Vec::new();
 
     for item in &impl_block.items {
         if let ImplItem::Fn(method) = item {
-            let is_public = matches!(method.vis, syn::Visibility::Public(_));
+            let is_public =
+                matches!(method.vis, syn::Visibility::Public(_)) || impl_block.trait_.is_some();
 
-            let has_ignore = method.attrs.iter().any(|attr| attr.path().is_ident("skip"));
+            let has_ignore = is_full_skip(&method.attrs);
 
             let has_overwrite = method
                 .attrs
@@ -56,10 +284,14 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
                 let sig = &method.sig;
                 let attrs = &method.attrs;
 
-                trait_methods.push(quote! {
-                    #(#attrs)*
-                    #sig;
-                });
+                if forward {
+                    trait_methods.push(forward_method(sig, attrs));
+                } else {
+                    trait_methods.push(quote! {
+                        #(#attrs)*
+                        #sig;
+                    });
+                }
             }
         }
     }
@@ -69,8 +301,9 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
 
         for item in &impl_block.items {
             if let ImplItem::Fn(method) = item {
-                let is_public = matches!(method.vis, syn::Visibility::Public(_));
-                let has_ignore = method.attrs.iter().any(|attr| attr.path().is_ident("skip"));
+                let is_public =
+                    matches!(method.vis, syn::Visibility::Public(_)) || impl_block.trait_.is_some();
+                let has_ignore = is_full_skip(&method.attrs);
 
                 let has_overwrite = method
                     .attrs
@@ -82,10 +315,14 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
                     let block = &method.block;
                     let attrs = &method.attrs;
 
-                    impl_methods.push(quote! {
-                        #(#attrs)*
-                        #sig #block
-                    });
+                    if trace {
+                        impl_methods.push(traced_method(sig, block, attrs, struct_name));
+                    } else {
+                        impl_methods.push(quote! {
+                            #(#attrs)*
+                            #sig #block
+                        });
+                    }
                 }
             }
         }
@@ -94,10 +331,34 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
         None
     };
 
+    let mock_methods = if mock {
+        let mut mock_methods = Vec::new();
+
+        for item in &impl_block.items {
+            if let ImplItem::Fn(method) = item {
+                let is_public =
+                    matches!(method.vis, syn::Visibility::Public(_)) || impl_block.trait_.is_some();
+                let has_ignore = is_full_skip(&method.attrs);
+
+                let has_overwrite = method
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("overwrite"));
+
+                if is_public && ((all && !has_ignore) || (!all && has_overwrite)) {
+                    mock_methods.push(&method.sig);
+                }
+            }
+        }
+        Some(mock_methods)
+    } else {
+        None
+    };
+
     let generics = &impl_block.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let trait_and_impl = if let Some(trait_name) = trait_name {
+    let trait_and_impl = if let Some(trait_name) = trait_name.clone() {
         if trait_methods.is_empty() {
             let span = format!("{:?}", impl_block.span());
             quote! {
@@ -106,8 +367,15 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
                 );
             }
         } else {
+            let supertrait = if forward {
+                quote! { : ::std::ops::DerefMut<Target = #self_ty> }
+            } else {
+                quote! {}
+            };
+
             let base = quote! {
-                pub trait #trait_name #impl_generics #where_clause {
+                pub trait #trait_name #impl_generics #supertrait #where_clause {
+                    #(#assoc_decls)*
                     #(#trait_methods)*
                 }
             };
@@ -116,6 +384,7 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
                     #base
 
                     impl #impl_generics #trait_name #ty_generics for #self_ty #where_clause {
+                        #(#assoc_defs)*
                         #(#impl_methods)*
                     }
                 }
@@ -129,18 +398,327 @@ pub fn generate_overwrites(attr: TokenStream, item: TokenStream) -> TokenStream
         quote! {}
     };
 
+    let mock_struct = match (trait_name, mock_methods) {
+        (Some(trait_name), Some(mock_methods)) if !mock_methods.is_empty() => {
+            build_mock(&trait_name, &mock_methods, &assoc_defs)
+        }
+        _ => quote! {},
+    };
+
     let expanded = quote! {
         #trait_and_impl
 
+        #mock_struct
+
         #impl_block
     };
 
     TokenStream::from(expanded)
 }
 
+/// A bare `#[skip]` (no parens) opts a method out of overwrite generation
+/// entirely. `#[skip(arg1, arg2)]` instead leaves the method in, but mirrors
+/// `tracing_attributes::instrument`'s `skip(...)` list, telling `trace` mode
+/// not to record those arguments in the emitted span.
+fn is_full_skip(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("skip") && matches!(attr.meta, syn::Meta::Path(_)))
+}
+
+fn traced_skip_args(attrs: &[syn::Attribute]) -> Vec<Ident> {
+    let mut skipped = Vec::new();
+
+    for attr in attrs {
+        if attr.path().is_ident("skip") {
+            if let syn::Meta::List(list) = &attr.meta {
+                if let Ok(idents) = list.parse_args_with(
+                    syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated,
+                ) {
+                    skipped.extend(idents);
+                }
+            }
+        }
+    }
+
+    skipped
+}
+
+/// Wraps a passthrough method's original body in a `tracing` span covering
+/// entry and exit, recording every non-skipped argument as a `Debug` field.
+fn traced_method(
+    sig: &syn::Signature,
+    block: &syn::Block,
+    attrs: &[syn::Attribute],
+    struct_name: Option<&Ident>,
+) -> proc_macro2::TokenStream {
+    let method_name = &sig.ident;
+    let span_name = match struct_name {
+        Some(struct_name) => format!("{struct_name}::{method_name}"),
+        None => method_name.to_string(),
+    };
+
+    let skipped = traced_skip_args(attrs);
+
+    let fields: Vec<_> = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                Pat::Ident(pat_ident) if !skipped.contains(&pat_ident.ident) => {
+                    let ident = &pat_ident.ident;
+                    Some(quote! { #ident = ?#ident })
+                }
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+
+    quote! {
+        #(#attrs)*
+        #sig {
+            let __span = tracing::span!(tracing::Level::TRACE, #span_name, #(#fields),*);
+            let _guard = __span.enter();
+            #block
+        }
+    }
+}
+
+/// Builds a default trait method that forwards the call to the wrapped
+/// value through `Deref`/`DerefMut`, reconstructing the argument list from
+/// `sig.inputs`.
+fn forward_method(sig: &syn::Signature, attrs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+    let name = &sig.ident;
+
+    let mut inputs = sig.inputs.iter();
+
+    let is_mut_receiver = match inputs.next() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {
+            receiver.mutability.is_some()
+        }
+        Some(FnArg::Receiver(receiver)) => {
+            return quote::quote_spanned! { receiver.span() =>
+                compile_error!("Cannot forward methods that take `self` by value, since they have no `Deref` receiver");
+            };
+        }
+        _ => {
+            return quote::quote_spanned! { sig.span() =>
+                compile_error!("Cannot forward associated functions, since they have no receiver to deref");
+            };
+        }
+    };
+
+    let mut args = Vec::new();
+    for input in inputs {
+        match input {
+            FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                Pat::Ident(pat_ident) => args.push(pat_ident.ident.clone()),
+                _ => {
+                    return quote::quote_spanned! { pat_type.span() =>
+                        compile_error!("Cannot forward methods with non-ident argument patterns");
+                    };
+                }
+            },
+            FnArg::Receiver(_) => unreachable!("receiver is always the first argument"),
+        }
+    }
+
+    let call = if is_mut_receiver {
+        quote! { ::std::ops::DerefMut::deref_mut(self).#name(#(#args),*) }
+    } else {
+        quote! { ::std::ops::Deref::deref(self).#name(#(#args),*) }
+    };
+
+    quote! {
+        #(#attrs)*
+        #sig {
+            #call
+        }
+    }
+}
+
+/// Whether `ty` names `Self` (e.g. `Self`, `Self::Item`, `Option<Self::Item>`).
+///
+/// Looks through path segments and their angle-bracketed type arguments,
+/// references, tuples, arrays, slices, parens and groups. Other type forms
+/// (raw pointers, function pointers, trait objects, `impl Trait`, the
+/// `<T as Trait>` part of qualified paths, `Fn(..)` arguments) are not
+/// searched and count as not naming `Self`.
+///
+/// Used to reject methods from `mock` generation whose signature can only be
+/// spelled inside an `impl` of the trait being mocked: the mock's closure
+/// fields live on a plain struct definition, where `Self` has no meaning.
+fn mentions_self_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            if segment.ident == "Self" {
+                return true;
+            }
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().any(
+                    |arg| matches!(arg, syn::GenericArgument::Type(ty) if mentions_self_type(ty)),
+                ),
+                _ => false,
+            }
+        }),
+        Type::Reference(reference) => mentions_self_type(&reference.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(mentions_self_type),
+        Type::Array(array) => mentions_self_type(&array.elem),
+        Type::Slice(slice) => mentions_self_type(&slice.elem),
+        Type::Paren(paren) => mentions_self_type(&paren.elem),
+        Type::Group(group) => mentions_self_type(&group.elem),
+        _ => false,
+    }
+}
+
+/// Builds a `mockall`-style test double implementing `trait_name`: a struct
+/// with one optional boxed closure and call counter per method, plus
+/// `expect_*` setters and `*_calls` accessors for each one.
+fn build_mock(
+    trait_name: &Ident,
+    methods: &[&syn::Signature],
+    assoc_defs: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let mock_ident = format_ident!(
+        "Mock{}",
+        trait_name
+            .to_string()
+            .strip_suffix("Overwrites")
+            .unwrap_or(&trait_name.to_string())
+    );
+
+    let mut fields = Vec::new();
+    let mut impl_methods = Vec::new();
+    let mut setters = Vec::new();
+    let mut accessors = Vec::new();
+
+    for sig in methods {
+        let name = &sig.ident;
+        let calls_field = format_ident!("{name}_calls");
+        let expect_ident = format_ident!("expect_{name}");
+
+        let arg_types: Vec<&Type> = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => Some(pat_type.ty.as_ref()),
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        let arg_idents: Vec<Ident> = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                    Pat::Ident(pat_ident) => Some(pat_ident.ident.clone()),
+                    _ => None,
+                },
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        let output_mentions_self = match &sig.output {
+            ReturnType::Default => false,
+            ReturnType::Type(_, ty) => mentions_self_type(ty),
+        };
+        if output_mentions_self || arg_types.iter().any(|ty| mentions_self_type(ty)) {
+            let message = format!(
+                "Cannot mock `{name}`: its signature names `Self` or an associated type, which \
+                 can't be written outside an `impl` of the trait being mocked. Skip it with \
+                 `#[skip]` or drop `mock` for this method.",
+            );
+            impl_methods.push(quote::quote_spanned! { sig.span() =>
+                #sig {
+                    compile_error!(#message)
+                }
+            });
+            continue;
+        }
+
+        let is_unit = match &sig.output {
+            ReturnType::Default => true,
+            ReturnType::Type(_, ty) => {
+                matches!(ty.as_ref(), Type::Tuple(tuple) if tuple.elems.is_empty())
+            }
+        };
+        let ret_ty = match &sig.output {
+            ReturnType::Default => quote! { () },
+            ReturnType::Type(_, ty) => quote! { #ty },
+        };
+
+        let unset = if is_unit {
+            quote! { () }
+        } else {
+            quote! {
+                panic!(
+                    "{}::{}: no expectation set, call {}() first",
+                    stringify!(#mock_ident),
+                    stringify!(#name),
+                    stringify!(#expect_ident),
+                )
+            }
+        };
+
+        fields.push(quote! {
+            #name: ::std::cell::RefCell<Option<Box<dyn FnMut(#(#arg_types),*) -> #ret_ty>>>,
+            #calls_field: ::std::cell::Cell<usize>,
+        });
+
+        impl_methods.push(quote! {
+            #sig {
+                self.#calls_field.set(self.#calls_field.get() + 1);
+                match self.#name.borrow_mut().as_mut() {
+                    Some(f) => f(#(#arg_idents),*),
+                    None => #unset,
+                }
+            }
+        });
+
+        setters.push(quote! {
+            pub fn #expect_ident(&mut self, f: impl FnMut(#(#arg_types),*) -> #ret_ty + 'static) -> &mut Self {
+                *self.#name.borrow_mut() = Some(Box::new(f));
+                self
+            }
+        });
+
+        accessors.push(quote! {
+            pub fn #calls_field(&self) -> usize {
+                self.#calls_field.get()
+            }
+        });
+    }
+
+    quote! {
+        #[derive(Default)]
+        pub struct #mock_ident {
+            #(#fields)*
+        }
+
+        impl #mock_ident {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#setters)*
+
+            #(#accessors)*
+        }
+
+        impl #trait_name for #mock_ident {
+            #(#assoc_defs)*
+            #(#impl_methods)*
+        }
+    }
+}
+
 struct GenerateArgs {
     all: bool,
     passthrough: bool,
+    forward: bool,
+    mock: bool,
+    trace: bool,
     name: Option<String>,
 }
 
@@ -148,6 +726,9 @@ impl Parse for GenerateArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut all = true;
         let mut passthrough = false;
+        let mut forward = false;
+        let mut mock = false;
+        let mut trace = false;
         let mut name = None;
 
         while !input.is_empty() {
@@ -175,6 +756,15 @@ impl Parse for GenerateArgs {
                 "passthrough" => {
                     passthrough = true;
                 }
+                "forward" => {
+                    forward = true;
+                }
+                "mock" => {
+                    mock = true;
+                }
+                "trace" => {
+                    trace = true;
+                }
                 _ => {
                     return Err(syn::Error::new(ident.span(), "Unknown argument"));
                 }
@@ -188,6 +778,9 @@ impl Parse for GenerateArgs {
         Ok(GenerateArgs {
             all,
             passthrough,
+            forward,
+            mock,
+            trace,
             name,
         })
     }